@@ -0,0 +1,86 @@
+//! [`rkyv`] adapters for the `alloy-primitives` types used by EIP-7702 authorizations.
+//!
+//! Fixed-size values are archived as their big-endian bytes, so archived authorizations can be
+//! accessed in place without deserializing them first.
+
+use alloy_primitives::{Address, U256, U8};
+use rkyv::{
+    rancor::Fallible,
+    with::{ArchiveWith, DeserializeWith, SerializeWith},
+    Archive, Place,
+};
+
+/// Archives a [`U256`] as its 32 big-endian bytes.
+#[derive(Debug)]
+pub struct U256Bytes;
+
+impl ArchiveWith<U256> for U256Bytes {
+    type Archived = [u8; 32];
+    type Resolver = [(); 32];
+
+    fn resolve_with(field: &U256, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        field.to_be_bytes::<32>().resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<U256, S> for U256Bytes {
+    fn serialize_with(_field: &U256, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok([(); 32])
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<[u8; 32], U256, D> for U256Bytes {
+    fn deserialize_with(field: &[u8; 32], _deserializer: &mut D) -> Result<U256, D::Error> {
+        Ok(U256::from_be_bytes(*field))
+    }
+}
+
+/// Archives an [`Address`] as its 20 bytes.
+#[derive(Debug)]
+pub struct AddressBytes;
+
+impl ArchiveWith<Address> for AddressBytes {
+    type Archived = [u8; 20];
+    type Resolver = [(); 20];
+
+    fn resolve_with(field: &Address, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        field.0 .0.resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<Address, S> for AddressBytes {
+    fn serialize_with(_field: &Address, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok([(); 20])
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<[u8; 20], Address, D> for AddressBytes {
+    fn deserialize_with(field: &[u8; 20], _deserializer: &mut D) -> Result<Address, D::Error> {
+        Ok(Address::new(*field))
+    }
+}
+
+/// Archives a [`U8`] as a plain `u8`.
+#[derive(Debug)]
+pub struct U8Byte;
+
+impl ArchiveWith<U8> for U8Byte {
+    type Archived = u8;
+    type Resolver = ();
+
+    fn resolve_with(field: &U8, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        field.to::<u8>().resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<U8, S> for U8Byte {
+    fn serialize_with(_field: &U8, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<u8, U8, D> for U8Byte {
+    fn deserialize_with(field: &u8, _deserializer: &mut D) -> Result<U8, D::Error> {
+        Ok(U8::from(*field))
+    }
+}
@@ -1,8 +1,10 @@
 use core::ops::Deref;
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
-use alloy_primitives::{keccak256, Address, PrimitiveSignature, SignatureError, B256, U256, U8};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{
+    keccak256, Address, Bytes, PrimitiveSignature, SignatureError, B256, U256, U8,
+};
 use alloy_rlp::{
     length_of_length, BufMut, Decodable, Encodable, Header, Result as RlpResult, RlpDecodable,
     RlpEncodable,
@@ -13,9 +15,11 @@ use core::hash::{Hash, Hasher};
 /// It can either be valid (containing an [`Address`]) or invalid (indicating recovery failure).
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
 pub enum RecoveredAuthority {
     /// Indicates a successfully recovered authority address.
-    Valid(Address),
+    Valid(#[cfg_attr(feature = "rkyv", rkyv(with = crate::archive::AddressBytes))] Address),
     /// Indicates a failed recovery attempt where no valid address could be recovered.
     Invalid,
 }
@@ -29,6 +33,13 @@ impl RecoveredAuthority {
         }
     }
 
+    /// Returns the [EIP-55] checksummed address string if valid.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    pub fn checksummed(&self) -> Option<String> {
+        self.address().map(|address| address.to_checksum(None))
+    }
+
     /// Returns true if the authority is valid.
     pub const fn is_valid(&self) -> bool {
         matches!(self, Self::Valid(_))
@@ -40,15 +51,30 @@ impl RecoveredAuthority {
     }
 }
 
+impl From<Option<Address>> for RecoveredAuthority {
+    fn from(value: Option<Address>) -> Self {
+        value.map_or(Self::Invalid, Self::Valid)
+    }
+}
+
+impl From<RecoveredAuthority> for Option<Address> {
+    fn from(value: RecoveredAuthority) -> Self {
+        value.address()
+    }
+}
+
 /// An unsigned EIP-7702 authorization.
 #[derive(Debug, Clone, Hash, RlpEncodable, RlpDecodable, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
 pub struct Authorization {
     /// The chain ID of the authorization.
+    #[cfg_attr(feature = "rkyv", rkyv(with = crate::archive::U256Bytes))]
     pub chain_id: U256,
     /// The address of the authorization.
+    #[cfg_attr(feature = "rkyv", rkyv(with = crate::archive::AddressBytes))]
     pub address: Address,
     /// The nonce for the authorization.
     #[cfg_attr(feature = "serde", serde(with = "quantity"))]
@@ -56,6 +82,29 @@ pub struct Authorization {
 }
 
 impl Authorization {
+    /// Creates an authorization that delegates the signing account to the code at `address`, on
+    /// the chain with `chain_id` (or any chain if 0), valid for the account's `nonce`.
+    ///
+    /// Returns [`Eip7702Error::ZeroDelegationAddress`](crate::Eip7702Error::ZeroDelegationAddress)
+    /// if `address` is the zero address, since that clears the delegation instead. Use
+    /// [`Authorization::clear_delegation`] for that.
+    pub fn delegate(
+        chain_id: U256,
+        address: Address,
+        nonce: u64,
+    ) -> Result<Self, crate::error::Eip7702Error> {
+        if address.is_zero() {
+            return Err(crate::error::Eip7702Error::ZeroDelegationAddress);
+        }
+        Ok(Self { chain_id, address, nonce })
+    }
+
+    /// Creates an authorization that clears the delegation of the signing account, on the chain
+    /// with `chain_id` (or any chain if 0), valid for the account's `nonce`.
+    pub const fn clear_delegation(chain_id: U256, nonce: u64) -> Self {
+        Self { chain_id, address: Address::ZERO, nonce }
+    }
+
     /// Get the `chain_id` for the authorization.
     ///
     /// # Note
@@ -65,6 +114,16 @@ impl Authorization {
         &self.chain_id
     }
 
+    /// Checks that the `chain_id` fits into a `u64`.
+    ///
+    /// Authorizations with a larger chain id can never match a chain and are unusable.
+    pub fn validate_chain_id(&self) -> Result<(), crate::error::Eip7702Error> {
+        if self.chain_id > U256::from(u64::MAX) {
+            return Err(crate::error::Eip7702Error::ChainIdTooLarge(self.chain_id));
+        }
+        Ok(())
+    }
+
     /// Get the `address` for the authorization.
     pub const fn address(&self) -> &Address {
         &self.address
@@ -75,6 +134,12 @@ impl Authorization {
         self.nonce
     }
 
+    /// Returns true if the authorization clears the delegation of the signing account, i.e. its
+    /// `address` is the zero address.
+    pub fn is_clearing(&self) -> bool {
+        self.address.is_zero()
+    }
+
     /// Computes the signature hash used to sign the authorization, or recover the authority from a
     /// signed authorization list item.
     ///
@@ -102,19 +167,27 @@ impl Authorization {
 }
 
 /// A signed EIP-7702 authorization.
+///
+/// When deserializing, the signature parity is accepted as `yParity`, as a legacy `v` (either
+/// `0`/`1` or `27`/`28`), or both, as long as they agree.
 #[derive(Debug, Clone, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
 pub struct SignedAuthorization {
     /// Inner authorization.
     #[cfg_attr(feature = "serde", serde(flatten))]
     inner: Authorization,
     /// Signature parity value. We allow any [`U8`] here, however, the only valid values are `0`
     /// and `1` and anything else will result in error during recovery.
-    #[cfg_attr(feature = "serde", serde(rename = "yParity", alias = "v"))]
+    #[cfg_attr(feature = "serde", serde(rename = "yParity"))]
+    #[cfg_attr(feature = "rkyv", rkyv(with = crate::archive::U8Byte))]
     y_parity: U8,
     /// Signature `r` value.
+    #[cfg_attr(feature = "rkyv", rkyv(with = crate::archive::U256Bytes))]
     r: U256,
     /// Signature `s` value.
+    #[cfg_attr(feature = "rkyv", rkyv(with = crate::archive::U256Bytes))]
     s: U256,
 }
 
@@ -124,6 +197,11 @@ impl SignedAuthorization {
         Self { inner, y_parity: U8::from(y_parity), r, s }
     }
 
+    /// Creates a new signed authorization from an authorization and its signature.
+    pub fn from_parts(inner: Authorization, signature: PrimitiveSignature) -> Self {
+        inner.into_signed(signature)
+    }
+
     /// Gets the `signature` for the authorization. Returns [`SignatureError`] if signature could
     /// not be constructed from vrs values.
     ///
@@ -162,6 +240,17 @@ impl SignedAuthorization {
         self.s
     }
 
+    /// Returns the RLP encoding of the signed authorization.
+    ///
+    /// Encoding recomputes the field lengths on every call, so callers serializing the same
+    /// authorization repeatedly, e.g. when broadcasting pooled transactions, should encode once
+    /// and keep the returned bytes.
+    pub fn encoded_bytes(&self) -> Bytes {
+        let mut buf = Vec::with_capacity(self.length());
+        self.encode(&mut buf);
+        buf.into()
+    }
+
     /// Decodes the transaction from RLP bytes, including the signature.
     fn decode_fields(buf: &mut &[u8]) -> RlpResult<Self> {
         Ok(Self {
@@ -187,6 +276,17 @@ impl SignedAuthorization {
     }
 }
 
+impl TryFrom<SignedAuthorization> for (Authorization, PrimitiveSignature) {
+    type Error = SignatureError;
+
+    /// Splits the signed authorization into its parts. Fails if the signature parity is invalid,
+    /// see [`SignedAuthorization::signature`].
+    fn try_from(value: SignedAuthorization) -> Result<Self, Self::Error> {
+        let signature = value.signature()?;
+        Ok((value.inner, signature))
+    }
+}
+
 impl Hash for SignedAuthorization {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.inner.hash(state);
@@ -235,6 +335,68 @@ impl Encodable for SignedAuthorization {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SignedAuthorization {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SignedAuthorizationHelper {
+            #[serde(flatten)]
+            inner: Authorization,
+            #[serde(default)]
+            y_parity: Option<U8>,
+            #[serde(default)]
+            v: Option<U8>,
+            r: U256,
+            s: U256,
+        }
+
+        let helper = SignedAuthorizationHelper::deserialize(deserializer)?;
+
+        // legacy `v` values are offset by 27
+        let v = helper.v.map(|v| match v.to::<u8>() {
+            27 | 28 => v - U8::from(27),
+            _ => v,
+        });
+
+        let y_parity = match (helper.y_parity, v) {
+            (Some(y_parity), Some(v)) if y_parity != v => {
+                return Err(serde::de::Error::custom("mismatched `v` and `yParity` values"));
+            }
+            (Some(y_parity), _) | (None, Some(y_parity)) => y_parity,
+            (None, None) => return Err(serde::de::Error::missing_field("yParity")),
+        };
+
+        Ok(Self { inner: helper.inner, y_parity, r: helper.r, s: helper.s })
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedSignedAuthorization {
+    /// Returns the archived inner [`Authorization`].
+    pub const fn inner(&self) -> &ArchivedAuthorization {
+        &self.inner
+    }
+
+    /// Returns the signature parity value.
+    pub const fn y_parity(&self) -> u8 {
+        self.y_parity
+    }
+
+    /// Returns the signature `r` value.
+    pub const fn r(&self) -> U256 {
+        U256::from_be_bytes(self.r)
+    }
+
+    /// Returns the signature `s` value.
+    pub const fn s(&self) -> U256 {
+        U256::from_be_bytes(self.s)
+    }
+}
+
 #[cfg(feature = "k256")]
 impl SignedAuthorization {
     /// Recover the authority for the authorization.
@@ -243,13 +405,46 @@ impl SignedAuthorization {
     ///
     /// Implementers should check that the authority has no code.
     pub fn recover_authority(&self) -> Result<Address, crate::error::Eip7702Error> {
+        self.recover_authority_with_hash(self.inner.signature_hash())
+    }
+
+    /// Recover the authority and check that it is `expected`.
+    ///
+    /// Returns [`Eip7702Error::AuthorityMismatch`](crate::Eip7702Error::AuthorityMismatch) if the
+    /// signature was made by a different account.
+    pub fn verify_authority(&self, expected: Address) -> Result<(), crate::error::Eip7702Error> {
+        let recovered = self.recover_authority()?;
+        if recovered != expected {
+            return Err(crate::error::Eip7702Error::AuthorityMismatch { expected, recovered });
+        }
+        Ok(())
+    }
+
+    /// Recover the authority using an already computed signature hash of the inner
+    /// authorization.
+    fn recover_authority_with_hash(
+        &self,
+        signature_hash: B256,
+    ) -> Result<Address, crate::error::Eip7702Error> {
         let signature = self.signature()?;
 
         if signature.s() > crate::constants::SECP256K1N_HALF {
             return Err(crate::error::Eip7702Error::InvalidSValue(signature.s()));
         }
 
-        Ok(signature.recover_address_from_prehash(&self.inner.signature_hash())?)
+        signature
+            .recover_address_from_prehash(&signature_hash)
+            .map_err(|source| crate::error::Eip7702Error::RecoveryFailed { signature_hash, source })
+    }
+
+    /// Recover the authorities for a list of authorizations.
+    ///
+    /// Every item is recovered, and the results are returned in the same order as the input, so
+    /// the index of a failed recovery matches the index of the offending authorization.
+    pub fn recover_all_with_errors(
+        auths: &[Self],
+    ) -> Vec<Result<Address, crate::error::Eip7702Error>> {
+        auths.iter().map(Self::recover_authority).collect()
     }
 
     /// Recover the authority and transform the signed authorization into a
@@ -271,6 +466,19 @@ impl Deref for SignedAuthorization {
     }
 }
 
+// Implemented by hand rather than derived so that `arbitrary` does not need
+// `alloy-primitives/arbitrary`, which does not build on `wasm32-unknown-unknown`.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Authorization {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            chain_id: U256::from_be_bytes(u.arbitrary::<[u8; 32]>()?),
+            address: Address::new(u.arbitrary()?),
+            nonce: u.arbitrary()?,
+        })
+    }
+}
+
 #[cfg(all(any(test, feature = "arbitrary"), feature = "k256"))]
 impl<'a> arbitrary::Arbitrary<'a> for SignedAuthorization {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
@@ -296,9 +504,100 @@ impl<'a> arbitrary::Arbitrary<'a> for SignedAuthorization {
     }
 }
 
+/// Encodes an `authorizationList` as it appears in an EIP-7702 set code transaction payload.
+///
+/// Authorizations are not standalone transactions and are never prefixed with the
+/// [transaction type](crate::constants::EIP7702_TX_TYPE_ID). The list is RLP encoded as one of the
+/// fields of the transaction payload, which is what the type byte precedes.
+pub fn encode_authorization_list(auths: &[SignedAuthorization], out: &mut dyn BufMut) {
+    alloy_rlp::encode_list(auths, out);
+}
+
+/// Decodes an `authorizationList` encoded with [`encode_authorization_list`].
+///
+/// See [`decode_authorization_list_bounded`] for decoding untrusted input.
+pub fn decode_authorization_list(buf: &mut &[u8]) -> RlpResult<Vec<SignedAuthorization>> {
+    Decodable::decode(buf)
+}
+
+/// Decodes an `authorizationList` encoded with [`encode_authorization_list`], failing if it
+/// contains more than `max_items` authorizations.
+///
+/// Items are decoded one by one and decoding stops as soon as the limit is exceeded, so untrusted
+/// input cannot make the decoder do unbounded work.
+pub fn decode_authorization_list_bounded(
+    buf: &mut &[u8],
+    max_items: usize,
+) -> RlpResult<Vec<SignedAuthorization>> {
+    let mut payload = Header::decode_bytes(buf, true)?;
+    let mut auths = Vec::new();
+    while !payload.is_empty() {
+        if auths.len() == max_items {
+            return Err(alloy_rlp::Error::Custom("too many authorizations"));
+        }
+        auths.push(SignedAuthorization::decode(&mut payload)?);
+    }
+    Ok(auths)
+}
+
+/// A [`SignedAuthorization`] with its [signature hash](Authorization::signature_hash) computed
+/// once, so repeated recoveries don't re-encode and re-hash the authorization.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct CachedAuthorization {
+    inner: SignedAuthorization,
+    signature_hash: B256,
+}
+
+impl CachedAuthorization {
+    /// Computes the signature hash of the authorization and caches it.
+    pub fn new(inner: SignedAuthorization) -> Self {
+        let signature_hash = inner.signature_hash();
+        Self { inner, signature_hash }
+    }
+
+    /// Returns the cached signature hash.
+    pub const fn signature_hash(&self) -> B256 {
+        self.signature_hash
+    }
+
+    /// Returns the inner [`SignedAuthorization`].
+    pub const fn inner(&self) -> &SignedAuthorization {
+        &self.inner
+    }
+
+    /// Consumes the type and returns the inner [`SignedAuthorization`].
+    pub const fn into_inner(self) -> SignedAuthorization {
+        self.inner
+    }
+
+    /// Recover the authority for the authorization, using the cached signature hash.
+    ///
+    /// See [`SignedAuthorization::recover_authority`].
+    #[cfg(feature = "k256")]
+    pub fn recover_authority(&self) -> Result<Address, crate::error::Eip7702Error> {
+        self.inner.recover_authority_with_hash(self.signature_hash)
+    }
+}
+
+impl From<SignedAuthorization> for CachedAuthorization {
+    fn from(value: SignedAuthorization) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Deref for CachedAuthorization {
+    type Target = SignedAuthorization;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
 /// A recovered authorization.
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
 pub struct RecoveredAuthorization {
     #[cfg_attr(feature = "serde", serde(flatten))]
     inner: Authorization,
@@ -318,12 +617,48 @@ impl RecoveredAuthorization {
         self.authority.address()
     }
 
+    /// Returns true if the authority is valid and equal to `address`.
+    pub fn has_authority(&self, address: Address) -> bool {
+        self.authority() == Some(address)
+    }
+
+    /// Returns an iterator over the authorizations whose recovered authority is `address`.
+    pub fn filter_by_authority<'a>(
+        auths: impl IntoIterator<Item = &'a Self>,
+        address: Address,
+    ) -> impl Iterator<Item = &'a Self> {
+        auths.into_iter().filter(move |auth| auth.has_authority(address))
+    }
+
+    /// Returns the [EIP-55] checksummed authority address string, if the authority is valid.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    pub fn authority_checksummed(&self) -> Option<String> {
+        self.authority.checksummed()
+    }
+
     /// Splits the authorization into parts.
     pub const fn into_parts(self) -> (Authorization, RecoveredAuthority) {
         (self.inner, self.authority)
     }
 }
 
+#[cfg(feature = "rkyv")]
+impl ArchivedRecoveredAuthorization {
+    /// Returns the archived inner [`Authorization`].
+    pub const fn inner(&self) -> &ArchivedAuthorization {
+        &self.inner
+    }
+
+    /// Returns the recovered authority address, if valid.
+    pub const fn authority(&self) -> Option<Address> {
+        match self.authority {
+            ArchivedRecoveredAuthority::Valid(address) => Some(Address::new(address)),
+            ArchivedRecoveredAuthority::Invalid => None,
+        }
+    }
+}
+
 #[cfg(feature = "k256")]
 impl From<SignedAuthorization> for RecoveredAuthority {
     fn from(value: SignedAuthorization) -> Self {
@@ -367,6 +702,46 @@ mod quantity {
     }
 }
 
+/// Alternative serde representations of [`RecoveredAuthority`].
+#[cfg(feature = "serde")]
+pub(super) mod recovered_authority {
+    /// Serializes a [`RecoveredAuthority`](crate::RecoveredAuthority) as an address, or `null` if
+    /// invalid.
+    ///
+    /// Intended to use with the `#[serde(with = "...")]` attribute:
+    /// ```rust
+    /// use alloy_eip7702::{recovered_authority, RecoveredAuthority};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Data {
+    ///     #[serde(with = "recovered_authority::as_option")]
+    ///     authority: RecoveredAuthority,
+    /// }
+    /// ```
+    pub mod as_option {
+        use crate::RecoveredAuthority;
+        use alloy_primitives::Address;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        /// Serializes a [`RecoveredAuthority`] as an optional address.
+        pub fn serialize<S>(value: &RecoveredAuthority, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            value.address().serialize(serializer)
+        }
+
+        /// Deserializes a [`RecoveredAuthority`] from an optional address.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<RecoveredAuthority, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<Address>::deserialize(deserializer).map(Into::into)
+        }
+    }
+}
+
 /// Bincode-compatible [`SignedAuthorization`] serde implementation.
 #[cfg(all(feature = "serde", feature = "serde-bincode-compat"))]
 pub(super) mod serde_bincode_compat {
@@ -482,6 +857,8 @@ mod tests {
     use super::*;
     use alloy_primitives::hex;
     use core::str::FromStr;
+    #[cfg(feature = "k256")]
+    use k256::ecdsa::SigningKey;
 
     fn test_encode_decode_roundtrip(auth: Authorization) {
         let mut buf = Vec::new();
@@ -491,6 +868,17 @@ mod tests {
         assert_eq!(decoded, auth);
     }
 
+    #[cfg(feature = "k256")]
+    fn sign(inner: Authorization, key: &SigningKey) -> SignedAuthorization {
+        use k256::ecdsa::signature::hazmat::PrehashSigner;
+
+        let (signature, recovery_id) = key.sign_prehash(inner.signature_hash().as_ref()).unwrap();
+        inner.into_signed(PrimitiveSignature::from_signature_and_parity(
+            signature,
+            recovery_id.is_y_odd(),
+        ))
+    }
+
     #[test]
     fn test_encode_decode_auth() {
         // fully filled
@@ -521,6 +909,160 @@ mod tests {
         assert_eq!(decoded, auth);
     }
 
+    #[test]
+    fn test_signed_auth_encoded_bytes() {
+        let auth = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 }
+            .into_signed(PrimitiveSignature::new(U256::from(2), U256::from(3), true));
+
+        let encoded = auth.encoded_bytes();
+        assert_eq!(encoded.len(), auth.length());
+        assert_eq!(encoded, alloy_rlp::encode(&auth));
+        assert_eq!(SignedAuthorization::decode(&mut encoded.as_ref()).unwrap(), auth);
+    }
+
+    #[test]
+    fn test_recovered_authority_option_conversions() {
+        let address = Address::left_padding_from(&[6]);
+
+        assert_eq!(RecoveredAuthority::from(Some(address)), RecoveredAuthority::Valid(address));
+        assert_eq!(RecoveredAuthority::from(None), RecoveredAuthority::Invalid);
+
+        assert_eq!(Option::<Address>::from(RecoveredAuthority::Valid(address)), Some(address));
+        assert_eq!(Option::<Address>::from(RecoveredAuthority::Invalid), None);
+    }
+
+    #[test]
+    fn test_filter_by_authority() {
+        let inner = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 };
+        let a = Address::left_padding_from(&[6]);
+        let b = Address::left_padding_from(&[7]);
+        let auths = [
+            RecoveredAuthorization::new_unchecked(inner.clone(), RecoveredAuthority::Valid(a)),
+            RecoveredAuthorization::new_unchecked(inner.clone(), RecoveredAuthority::Valid(b)),
+            RecoveredAuthorization::new_unchecked(inner.clone(), RecoveredAuthority::Invalid),
+            RecoveredAuthorization::new_unchecked(inner, RecoveredAuthority::Valid(a)),
+        ];
+
+        assert!(auths[0].has_authority(a));
+        assert!(!auths[0].has_authority(b));
+        assert!(!auths[2].has_authority(Address::ZERO));
+
+        let from_a = RecoveredAuthorization::filter_by_authority(&auths, a).collect::<Vec<_>>();
+        assert_eq!(from_a, [&auths[0], &auths[3]]);
+        assert_eq!(RecoveredAuthorization::filter_by_authority(&auths, b).count(), 1);
+        assert_eq!(RecoveredAuthorization::filter_by_authority(&auths, Address::ZERO).count(), 0);
+    }
+
+    #[test]
+    fn test_authority_checksummed() {
+        let address = Address::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+        let inner = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 };
+
+        let auth = RecoveredAuthorization::new_unchecked(
+            inner.clone(),
+            RecoveredAuthority::Valid(address),
+        );
+        assert_eq!(
+            auth.authority_checksummed().as_deref(),
+            Some("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed")
+        );
+
+        let auth = RecoveredAuthorization::new_unchecked(inner, RecoveredAuthority::Invalid);
+        assert_eq!(auth.authority_checksummed(), None);
+    }
+
+    #[test]
+    fn test_delegate_and_clear_delegation() {
+        let address = Address::left_padding_from(&[6]);
+        let auth = Authorization::delegate(U256::from(1), address, 2).unwrap();
+        assert_eq!(auth, Authorization { chain_id: U256::from(1), address, nonce: 2 });
+
+        assert!(matches!(
+            Authorization::delegate(U256::from(1), Address::ZERO, 2),
+            Err(crate::Eip7702Error::ZeroDelegationAddress)
+        ));
+
+        let auth = Authorization::clear_delegation(U256::from(1), 2);
+        assert_eq!(
+            auth,
+            Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 2 }
+        );
+    }
+
+    #[test]
+    fn test_is_clearing() {
+        assert!(Authorization::clear_delegation(U256::from(1), 2).is_clearing());
+
+        let address = Address::left_padding_from(&[6]);
+        assert!(!Authorization::delegate(U256::from(1), address, 2).unwrap().is_clearing());
+    }
+
+    #[test]
+    fn test_validate_chain_id() {
+        let mut auth = Authorization { chain_id: U256::ZERO, address: Address::ZERO, nonce: 0 };
+        assert!(auth.validate_chain_id().is_ok());
+
+        auth.chain_id = U256::from(u64::MAX);
+        assert!(auth.validate_chain_id().is_ok());
+
+        auth.chain_id = U256::MAX;
+        assert!(matches!(
+            auth.validate_chain_id(),
+            Err(crate::Eip7702Error::ChainIdTooLarge(chain_id)) if chain_id == U256::MAX
+        ));
+    }
+
+    #[test]
+    fn test_encode_decode_authorization_list() {
+        let auths = (0..3)
+            .map(|nonce| {
+                Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce }
+                    .into_signed(PrimitiveSignature::new(U256::from(2), U256::from(3), false))
+            })
+            .collect::<Vec<_>>();
+
+        let mut buf = Vec::new();
+        encode_authorization_list(&auths, &mut buf);
+        assert_eq!(buf, alloy_rlp::encode(&auths));
+
+        let decoded = decode_authorization_list(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, auths);
+
+        let mut buf = Vec::new();
+        encode_authorization_list(&[], &mut buf);
+        assert_eq!(buf, [alloy_rlp::EMPTY_LIST_CODE]);
+        assert!(decode_authorization_list(&mut buf.as_slice()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_decode_authorization_list_bounded() {
+        let auths = (0..3)
+            .map(|nonce| {
+                Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce }
+                    .into_signed(PrimitiveSignature::new(U256::from(2), U256::from(3), false))
+            })
+            .collect::<Vec<_>>();
+        let encoded = alloy_rlp::encode(&auths);
+
+        // exactly at the limit
+        let mut buf = encoded.as_slice();
+        assert_eq!(decode_authorization_list_bounded(&mut buf, 3).unwrap(), auths);
+        assert!(buf.is_empty());
+
+        // over the limit
+        assert_eq!(
+            decode_authorization_list_bounded(&mut encoded.as_slice(), 2),
+            Err(alloy_rlp::Error::Custom("too many authorizations"))
+        );
+        assert_eq!(
+            decode_authorization_list_bounded(&mut encoded.as_slice(), 0),
+            Err(alloy_rlp::Error::Custom("too many authorizations"))
+        );
+
+        // truncated input
+        assert!(decode_authorization_list_bounded(&mut &encoded[..encoded.len() - 1], 3).is_err());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_auth_json() {
@@ -536,6 +1078,202 @@ mod tests {
         assert_eq!(val, s);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_signed_auth_json_v_and_y_parity() {
+        let expected = Authorization {
+            chain_id: U256::from(1),
+            address: Address::left_padding_from(&[6]),
+            nonce: 1,
+        }
+        .into_signed(PrimitiveSignature::new(U256::from(2), U256::from(3), true));
+
+        for parity in [
+            r#""yParity":"0x1""#,
+            r#""v":"0x1""#,
+            r#""v":"0x1c""#,
+            r#""v":"0x1","yParity":"0x1""#,
+            r#""v":"0x1c","yParity":"0x1""#,
+        ] {
+            let json = format!(
+                r#"{{"chainId":"0x1","address":"0x0000000000000000000000000000000000000006","nonce":"0x1",{parity},"r":"0x2","s":"0x3"}}"#
+            );
+            let auth: SignedAuthorization = serde_json::from_str(&json).unwrap();
+            assert_eq!(auth, expected, "{parity}");
+        }
+
+        for parity in ["", r#""v":"0x1b","yParity":"0x1","#] {
+            let json = format!(
+                r#"{{"chainId":"0x1","address":"0x0000000000000000000000000000000000000006","nonce":"0x1",{parity}"r":"0x2","s":"0x3"}}"#
+            );
+            assert!(serde_json::from_str::<SignedAuthorization>(&json).is_err(), "{parity}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_recovered_authority_as_option() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Data {
+            #[serde(with = "crate::recovered_authority::as_option")]
+            authority: RecoveredAuthority,
+        }
+
+        let valid = Data { authority: RecoveredAuthority::Valid(Address::left_padding_from(&[6])) };
+        let json = serde_json::to_string(&valid).unwrap();
+        assert_eq!(json, r#"{"authority":"0x0000000000000000000000000000000000000006"}"#);
+        assert_eq!(serde_json::from_str::<Data>(&json).unwrap(), valid);
+
+        let invalid = Data { authority: RecoveredAuthority::Invalid };
+        let json = serde_json::to_string(&invalid).unwrap();
+        assert_eq!(json, r#"{"authority":null}"#);
+        assert_eq!(serde_json::from_str::<Data>(&json).unwrap(), invalid);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_auth_json_nonce_u64_max() {
+        let auth =
+            Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: u64::MAX };
+        let val = serde_json::to_string(&auth).unwrap();
+        assert!(val.contains(r#""nonce":"0xffffffffffffffff""#));
+        assert_eq!(serde_json::from_str::<Authorization>(&val).unwrap(), auth);
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn test_recovery_failed_carries_signature_hash() {
+        let auth = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 };
+        let signature_hash = auth.signature_hash();
+        let auth = auth.into_signed(PrimitiveSignature::new(U256::ZERO, U256::from(1), false));
+
+        assert!(matches!(
+            auth.recover_authority(),
+            Err(crate::Eip7702Error::RecoveryFailed { signature_hash: hash, .. })
+                if hash == signature_hash
+        ));
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn test_verify_authority() {
+        let signing_key = SigningKey::from_bytes(&[1u8; 32].into()).unwrap();
+        let authority = Address::from_private_key(&signing_key);
+        let inner = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 };
+        let auth = sign(inner, &signing_key);
+
+        assert!(auth.verify_authority(authority).is_ok());
+
+        let other = Address::left_padding_from(&[6]);
+        assert!(matches!(
+            auth.verify_authority(other),
+            Err(crate::Eip7702Error::AuthorityMismatch { expected, recovered })
+                if expected == other && recovered == authority
+        ));
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn test_recover_all_with_errors() {
+        let signing_key = SigningKey::from_bytes(&[1u8; 32].into()).unwrap();
+        let inner = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 };
+        let valid = sign(inner.clone(), &signing_key);
+        let high_s = SignedAuthorization::new_unchecked(inner.clone(), 0, valid.r(), U256::MAX);
+        let unrecoverable = SignedAuthorization::new_unchecked(inner, 0, U256::ZERO, U256::from(1));
+
+        let results = SignedAuthorization::recover_all_with_errors(&[valid, high_s, unrecoverable]);
+        assert_eq!(results.len(), 3);
+        assert_eq!(*results[0].as_ref().unwrap(), Address::from_private_key(&signing_key));
+        assert!(matches!(results[1], Err(crate::Eip7702Error::InvalidSValue(s)) if s == U256::MAX));
+        assert!(matches!(results[2], Err(crate::Eip7702Error::RecoveryFailed { .. })));
+    }
+
+    #[test]
+    fn test_signed_auth_parts_roundtrip() {
+        let inner = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 };
+        let signature = PrimitiveSignature::new(U256::from(2), U256::from(3), true);
+
+        let auth = SignedAuthorization::from_parts(inner.clone(), signature);
+        assert_eq!(auth, inner.clone().into_signed(signature));
+
+        let parts: (Authorization, PrimitiveSignature) = auth.try_into().unwrap();
+        assert_eq!(parts, (inner.clone(), signature));
+
+        let invalid = SignedAuthorization::new_unchecked(inner, 2, U256::from(2), U256::from(3));
+        assert!(matches!(
+            <(Authorization, PrimitiveSignature)>::try_from(invalid),
+            Err(SignatureError::InvalidParity(2))
+        ));
+    }
+
+    #[test]
+    fn test_cached_authorization() {
+        let auth = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 }
+            .into_signed(PrimitiveSignature::new(U256::from(2), U256::from(3), false));
+        let cached = CachedAuthorization::from(auth.clone());
+
+        assert_eq!(cached.signature_hash(), auth.signature_hash());
+        assert_eq!(cached.into_inner(), auth);
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn test_cached_authorization_recover() {
+        let signing_key = SigningKey::from_bytes(&[1u8; 32].into()).unwrap();
+        let inner = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 };
+        let auth = sign(inner, &signing_key);
+
+        let cached = CachedAuthorization::new(auth.clone());
+        assert_eq!(cached.recover_authority().unwrap(), auth.recover_authority().unwrap());
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_signed_auth_roundtrip() {
+        let auth = Authorization {
+            chain_id: U256::from(1),
+            address: Address::left_padding_from(&[6]),
+            nonce: 1,
+        }
+        .into_signed(PrimitiveSignature::new(U256::from(2), U256::MAX, true));
+
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&auth).unwrap();
+        let archived =
+            rkyv::access::<ArchivedSignedAuthorization, rkyv::rancor::Error>(&bytes).unwrap();
+
+        assert_eq!(archived.inner().chain_id, U256::from(1).to_be_bytes::<32>());
+        assert_eq!(archived.inner().address, auth.address().0 .0);
+        assert_eq!(archived.inner().nonce, 1);
+        assert_eq!(archived.y_parity(), 1);
+        assert_eq!(archived.r(), auth.r());
+        assert_eq!(archived.s(), auth.s());
+
+        let decoded =
+            rkyv::deserialize::<SignedAuthorization, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(decoded, auth);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_recovered_auth_roundtrip() {
+        let inner = Authorization { chain_id: U256::from(1), address: Address::ZERO, nonce: 1 };
+        let address = Address::left_padding_from(&[6]);
+
+        for authority in [RecoveredAuthority::Valid(address), RecoveredAuthority::Invalid] {
+            let auth = RecoveredAuthorization::new_unchecked(inner.clone(), authority);
+
+            let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&auth).unwrap();
+            let archived =
+                rkyv::access::<ArchivedRecoveredAuthorization, rkyv::rancor::Error>(&bytes)
+                    .unwrap();
+            assert_eq!(archived.authority(), auth.authority());
+
+            let decoded =
+                rkyv::deserialize::<RecoveredAuthorization, rkyv::rancor::Error>(archived).unwrap();
+            assert_eq!(decoded, auth);
+        }
+    }
+
     #[cfg(all(feature = "arbitrary", feature = "k256"))]
     #[test]
     fn test_arbitrary_auth() {
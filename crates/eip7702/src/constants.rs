@@ -1,7 +1,7 @@
 //! [EIP-7702] constants.
 //!
 //! [EIP-7702]: https://eips.ethereum.org/EIPS/eip-7702
-use alloy_primitives::{uint, U256};
+use alloy_primitives::{hex, uint, U256};
 
 /// Identifier for EIP7702's set code transaction.
 ///
@@ -13,6 +13,18 @@ pub const EIP7702_TX_TYPE_ID: u8 = 4;
 /// See also [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702).
 pub const MAGIC: u8 = 0x05;
 
+/// Prefix of the code of an account delegated by an EIP7702 authorization. It is followed by the
+/// 20 byte address of the delegate.
+///
+/// See also [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702).
+pub const EIP7702_DELEGATION_DESIGNATOR: [u8; 3] = [0xef, 0x01, 0x00];
+
+/// Delegation designator pointing to the zero address, i.e. a cleared delegation.
+///
+/// See also [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702).
+pub const EIP7702_CLEARED_DELEGATION: [u8; 23] =
+    hex!("ef01000000000000000000000000000000000000000000");
+
 /// An additional gas cost per EIP7702 authorization list item.
 ///
 /// See also [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702).
@@ -0,0 +1,114 @@
+//! Helpers for the [EIP-7702] delegation designator.
+//!
+//! [EIP-7702]: https://eips.ethereum.org/EIPS/eip-7702
+
+use crate::constants::{EIP7702_CLEARED_DELEGATION, EIP7702_DELEGATION_DESIGNATOR};
+use alloy_primitives::{Address, Bytes};
+
+/// Length of the code of a delegated account: the designator followed by the delegate address.
+const DELEGATION_CODE_LENGTH: usize = EIP7702_DELEGATION_DESIGNATOR.len() + Address::len_bytes();
+
+/// Returns the delegate address if `code` is a delegation designator.
+///
+/// A [cleared](is_cleared) designator is not a delegation and returns `None`.
+pub fn delegated_address(code: &[u8]) -> Option<Address> {
+    if code.len() != DELEGATION_CODE_LENGTH {
+        return None;
+    }
+    code.strip_prefix(&EIP7702_DELEGATION_DESIGNATOR)
+        .map(Address::from_slice)
+        .filter(|address| !address.is_zero())
+}
+
+/// Returns true if `code` is the [cleared delegation](EIP7702_CLEARED_DELEGATION) designator.
+///
+/// This is distinct from code that is not a designator at all. Neither has a delegate, but a
+/// cleared designator leaves the account with no code to run, while other code runs as is.
+pub fn is_cleared(code: &[u8]) -> bool {
+    code == EIP7702_CLEARED_DELEGATION
+}
+
+/// Resolves the effective code of an account with the given `code`.
+///
+/// If `code` is a delegation designator, the code of the delegate is returned by calling `fetch`
+/// with the delegate address. Delegations are resolved a single level deep as mandated by
+/// EIP-7702, so a delegate whose code is itself a designator is returned as is. Otherwise `code`
+/// is the effective code.
+///
+/// Returns `None` if there is no code to execute, including for a [cleared](is_cleared)
+/// designator.
+pub fn resolve_code(code: &[u8], fetch: impl FnOnce(Address) -> Option<Bytes>) -> Option<Bytes> {
+    match delegated_address(code) {
+        Some(address) => fetch(address),
+        None if code.is_empty() || is_cleared(code) => None,
+        None => Some(Bytes::copy_from_slice(code)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use alloy_primitives::bytes;
+
+    fn designator(address: Address) -> Vec<u8> {
+        [&EIP7702_DELEGATION_DESIGNATOR[..], address.as_slice()].concat()
+    }
+
+    #[test]
+    fn test_delegated_address() {
+        let address = Address::left_padding_from(&[6]);
+        let code = designator(address);
+        assert_eq!(delegated_address(&code), Some(address));
+
+        assert_eq!(delegated_address(&[]), None);
+        assert_eq!(delegated_address(&code[..code.len() - 1]), None);
+        assert_eq!(delegated_address(&[&code[..], &[0]].concat()), None);
+
+        let mut code = code;
+        code[0] = 0xee;
+        assert_eq!(delegated_address(&code), None);
+    }
+
+    #[test]
+    fn test_is_cleared() {
+        let cleared = designator(Address::ZERO);
+        assert_eq!(cleared, EIP7702_CLEARED_DELEGATION);
+        assert!(is_cleared(&cleared));
+        assert_eq!(delegated_address(&cleared), None);
+
+        // live delegation
+        let live = designator(Address::left_padding_from(&[6]));
+        assert!(!is_cleared(&live));
+        assert!(delegated_address(&live).is_some());
+
+        // not a delegation
+        assert!(!is_cleared(&[]));
+        assert!(!is_cleared(&[0x60, 0x01]));
+        assert!(!is_cleared(&cleared[..cleared.len() - 1]));
+    }
+
+    #[test]
+    fn test_resolve_code() {
+        let delegate = Address::left_padding_from(&[6]);
+        let delegate_code = bytes!("6000");
+        let fetch = |address: Address| (address == delegate).then(|| delegate_code.clone());
+
+        // non-delegated account
+        assert_eq!(resolve_code(&[0x60, 0x01], fetch), Some(bytes!("6001")));
+        assert_eq!(resolve_code(&[], fetch), None);
+
+        // valid delegation
+        assert_eq!(resolve_code(&designator(delegate), fetch), Some(delegate_code.clone()));
+
+        // cleared delegation
+        assert_eq!(resolve_code(&EIP7702_CLEARED_DELEGATION, |_| unreachable!()), None);
+
+        // delegate without code
+        assert_eq!(resolve_code(&designator(Address::left_padding_from(&[7])), fetch), None);
+
+        // chained delegations are not followed
+        let chained = Bytes::from(designator(Address::left_padding_from(&[7])));
+        assert_eq!(resolve_code(&designator(delegate), |_| Some(chained.clone())), Some(chained));
+    }
+}
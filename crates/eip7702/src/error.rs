@@ -1,4 +1,4 @@
-use alloy_primitives::U256;
+use alloy_primitives::{Address, B256, U256};
 
 /// EIP-7702 error.
 #[derive(Debug, derive_more::Display, derive_more::From)]
@@ -6,17 +6,64 @@ pub enum Eip7702Error {
     /// Invalid signature `s` value.
     #[display("invalid signature `s` value: {_0}")]
     InvalidSValue(U256),
+    /// Authorization chain id does not fit into a `u64`.
+    #[display("authorization chain id too large: {_0}")]
+    ChainIdTooLarge(U256),
+    /// Delegation to the zero address, which clears the delegation instead.
+    #[display("delegation to the zero address, use `Authorization::clear_delegation` instead")]
+    ZeroDelegationAddress,
+    /// Recovered authority does not match the expected one.
+    #[display("authority mismatch: expected {expected}, recovered {recovered}")]
+    AuthorityMismatch {
+        /// The expected authority.
+        expected: Address,
+        /// The authority recovered from the signature.
+        recovered: Address,
+    },
     /// Signature error.
     #[from]
     Signature(alloy_primitives::SignatureError),
+    /// Failed to recover the authority from the signature.
+    #[display("failed to recover authority for signature hash {signature_hash}: {source}")]
+    RecoveryFailed {
+        /// Signature hash of the authorization that failed recovery.
+        signature_hash: B256,
+        /// The underlying recovery error.
+        source: alloy_primitives::SignatureError,
+    },
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for Eip7702Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::InvalidSValue(_) => None,
-            Self::Signature(err) => Some(err),
+            Self::InvalidSValue(_)
+            | Self::ChainIdTooLarge(_)
+            | Self::ZeroDelegationAddress
+            | Self::AuthorityMismatch { .. } => None,
+            Self::Signature(err) | Self::RecoveryFailed { source: err, .. } => Some(err),
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl From<Eip7702Error> for std::io::Error {
+    fn from(err: Eip7702Error) -> Self {
+        Self::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_io_error() {
+        let err = Eip7702Error::InvalidSValue(U256::from(1));
+        let message = err.to_string();
+
+        let io_err = std::io::Error::from(err);
+        assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(io_err.to_string(), message);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::constants::{PER_AUTH_BASE_COST, PER_EMPTY_ACCOUNT_COST};
+
+/// Computes the gas an authorization list adds to a set code transaction, minus the refund for
+/// authorities that already exist in the trie.
+///
+/// Every authorization is charged [`PER_EMPTY_ACCOUNT_COST`] as part of the intrinsic gas, and each
+/// authority that already exists is refunded `PER_EMPTY_ACCOUNT_COST - PER_AUTH_BASE_COST`. The
+/// number of existing accounts is clamped to the number of authorizations.
+///
+/// # Note
+///
+/// The refund is not deducted upfront. It is credited to the refund counter, which [EIP-3529] caps
+/// at a fifth of the gas used by the transaction, so with `existing_account_count > 0` the result
+/// is only a lower bound on the gas the transaction is charged. A gas limit must be computed with
+/// `intrinsic_gas(authorization_count, 0)`, otherwise the transaction fails the intrinsic gas
+/// check.
+///
+/// [EIP-3529]: https://eips.ethereum.org/EIPS/eip-3529
+pub const fn intrinsic_gas(authorization_count: usize, existing_account_count: usize) -> u64 {
+    let existing_account_count = if existing_account_count < authorization_count {
+        existing_account_count
+    } else {
+        authorization_count
+    };
+
+    let cost = (authorization_count as u64).saturating_mul(PER_EMPTY_ACCOUNT_COST);
+    let refund =
+        (existing_account_count as u64).saturating_mul(PER_EMPTY_ACCOUNT_COST - PER_AUTH_BASE_COST);
+    cost.saturating_sub(refund)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intrinsic_gas() {
+        assert_eq!(intrinsic_gas(0, 0), 0);
+        // single authorization for a new account
+        assert_eq!(intrinsic_gas(1, 0), 25_000);
+        // single authorization for an existing account
+        assert_eq!(intrinsic_gas(1, 1), 12_500);
+        // three authorizations, two of them for existing accounts
+        assert_eq!(intrinsic_gas(3, 2), 50_000);
+        // existing accounts are clamped to the number of authorizations
+        assert_eq!(intrinsic_gas(2, 5), 25_000);
+    }
+}
@@ -12,9 +12,26 @@ pub use auth_list::*;
 
 pub mod constants;
 
+pub mod delegation;
+
+mod gas;
+pub use gas::intrinsic_gas;
+
+#[cfg(feature = "rkyv")]
+pub mod archive;
+
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
 mod error;
 pub use error::Eip7702Error;
 
+/// Alternative serde representations of [`RecoveredAuthority`].
+#[cfg(feature = "serde")]
+pub mod recovered_authority {
+    pub use super::auth_list::recovered_authority::*;
+}
+
 /// Bincode-compatible serde implementations for EIP-7702 types.
 ///
 /// `bincode` crate doesn't work with `#[serde(flatten)]` attribute, but some of the EIP-7702 types
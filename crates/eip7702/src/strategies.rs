@@ -0,0 +1,49 @@
+//! [`proptest`](mod@proptest) strategies for EIP-7702 types.
+
+use crate::Authorization;
+use alloy_primitives::{Address, U256};
+use proptest::prelude::*;
+
+/// Returns a strategy producing arbitrary [`Authorization`]s.
+pub fn any_authorization() -> impl Strategy<Value = Authorization> {
+    (any::<[u8; 32]>(), any::<[u8; 20]>(), any::<u64>()).prop_map(|(chain_id, address, nonce)| {
+        Authorization {
+            chain_id: U256::from_be_bytes(chain_id),
+            address: Address::new(address),
+            nonce,
+        }
+    })
+}
+
+/// Returns a strategy producing [`SignedAuthorization`](crate::SignedAuthorization)s signed by a
+/// random key, so the authority can always be recovered.
+#[cfg(feature = "k256")]
+pub fn any_signed_authorization() -> impl Strategy<Value = crate::SignedAuthorization> {
+    use alloy_primitives::PrimitiveSignature;
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey};
+
+    let signing_key = any::<[u8; 32]>()
+        .prop_filter_map("invalid secret key", |bytes| SigningKey::from_bytes(&bytes.into()).ok());
+
+    (any_authorization(), signing_key).prop_map(|(inner, signing_key)| {
+        let (signature, recovery_id) =
+            signing_key.sign_prehash(inner.signature_hash().as_ref()).unwrap();
+        inner.into_signed(PrimitiveSignature::from_signature_and_parity(
+            signature,
+            recovery_id.is_y_odd(),
+        ))
+    })
+}
+
+#[cfg(all(test, feature = "k256"))]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn signed_authorization_recovers(auth in any_signed_authorization()) {
+            prop_assert!(auth.recover_authority().is_ok());
+            prop_assert!(auth.into_recovered().authority().is_some());
+        }
+    }
+}
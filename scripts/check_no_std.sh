@@ -1,9 +1,18 @@
 #!/usr/bin/env bash
 set -eo pipefail
 
+# Supported `no_std` feature combinations. Every crate is built without default features, so each
+# entry checks that the listed optional features do not pull in `std`.
 no_std_packages=(
-    alloy-eip7702
-    alloy-eip2930
+    "alloy-eip2124"
+    "alloy-eip2124 --features serde"
+    "alloy-eip2930"
+    "alloy-eip2930 --features serde"
+    "alloy-eip7702"
+    "alloy-eip7702 --features serde"
+    "alloy-eip7702 --features k256"
+    "alloy-eip7702 --features serde,k256"
+    "alloy-eip7702 --features rkyv"
 )
 
 for package in "${no_std_packages[@]}"; do
@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+set -eo pipefail
+
+wasm_packages=(
+    "alloy-eip7702 --features serde,k256,arbitrary"
+)
+
+for package in "${wasm_packages[@]}"; do
+  cmd="cargo +stable build -p $package --target wasm32-unknown-unknown"
+  if [ -n "$CI" ]; then
+    echo "::group::$cmd"
+  else
+    printf "\n%s:\n  %s\n" "$package" "$cmd"
+  fi
+
+  $cmd
+
+  if [ -n "$CI" ]; then
+    echo "::endgroup::"
+  fi
+done